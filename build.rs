@@ -0,0 +1,32 @@
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    // container builds have no `git`, so they pass the commit hash in through `GIT_SHA`
+    println!("cargo:rerun-if-env-changed=GIT_SHA");
+    let git_sha = env::var("GIT_SHA")
+        .ok()
+        .filter(|sha| !sha.is_empty())
+        .or_else(git_rev_parse)
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=GIT_SHA={}", git_sha);
+
+    // watching paths that don't exist would make cargo rerun this script on every build
+    for path in [".git/HEAD", ".git/refs", ".git/packed-refs"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+}
+
+fn git_rev_parse() -> Option<String> {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|sha| sha.trim().to_string())
+}
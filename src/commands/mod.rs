@@ -1,8 +1,59 @@
 pub mod test;
 
+use tracing::{error, field, info, info_span, warn, Span};
+
 pub type Context<'a> = poise::Context<'a, (), anyhow::Error>;
 pub type Command = poise::Command<(), anyhow::Error>;
+pub type FrameworkError<'a> = poise::FrameworkError<'a, (), anyhow::Error>;
 
 pub fn commands() -> Vec<Command> {
     [].into_iter().chain(test::commands()).collect()
 }
+
+fn command_span(ctx: Context<'_>) -> Span {
+    info_span!(
+        "command",
+        name = ctx.command().qualified_name,
+        guild_id = ctx.guild_id().map(u64::from),
+        user_id = u64::from(ctx.author().id),
+        outcome = field::Empty,
+    )
+}
+
+// Returns the span opened by `pre_command`, or a fresh one if the invocation failed before it ran
+// (e.g. a check or cooldown rejected it).
+async fn invocation_span(ctx: Context<'_>) -> Span {
+    match ctx.invocation_data::<Span>().await {
+        Some(span) => span.clone(),
+        None => command_span(ctx),
+    }
+}
+
+pub async fn pre_command(ctx: Context<'_>) {
+    let span = command_span(ctx);
+    span.in_scope(|| info!("Command invoked"));
+    ctx.set_invocation_data(span).await;
+}
+
+pub async fn post_command(ctx: Context<'_>) {
+    let span = invocation_span(ctx).await;
+    span.record("outcome", "success");
+    span.in_scope(|| info!("Command completed"));
+}
+
+pub async fn on_error(error: FrameworkError<'_>) {
+    if let Some(ctx) = error.ctx() {
+        let span = invocation_span(ctx).await;
+        span.record("outcome", "failure");
+        span.in_scope(|| match &error {
+            poise::FrameworkError::Command { error, .. } => {
+                warn!(error = %error, "Command failed")
+            }
+            error => warn!("Command failed: {}", error),
+        });
+    }
+
+    if let Err(error) = poise::builtins::on_error(error).await {
+        error!("Failed to handle a framework error: {}", error);
+    }
+}
@@ -1,10 +1,21 @@
 use crate::commands::{Command, Context};
 use anyhow::Result;
+use poise::CreateReply;
+use serenity::builder::CreateEmbed;
+use std::time::Duration;
 
 #[poise::command(slash_command)]
 pub async fn ping(ctx: Context<'_>) -> Result<()> {
-    let response = "Pong!".to_string();
-    ctx.say(response).await?;
+    // the shard reports zero until its first heartbeat has been acknowledged
+    let gateway_latency = match ctx.ping().await {
+        Duration::ZERO => "N/A".to_string(),
+        latency => format!("{} ms", latency.as_millis()),
+    };
+
+    let embed = CreateEmbed::new()
+        .title("Pong!")
+        .field("Gateway", gateway_latency, true);
+    ctx.send(CreateReply::default().embed(embed)).await?;
     Ok(())
 }
 
@@ -7,6 +7,18 @@ use serenity::prelude::{Client, Context, EventHandler, GatewayIntents};
 use std::env;
 use tracing::{info, instrument, Level};
 
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+const GIT_SHA: &str = env!("GIT_SHA");
+
+const BANNER: &str = r"
+                 _           _
+  ___  __ _ _ __| |__   ___ | |_
+ / __|/ _` | '__| '_ \ / _ \| __|
+ \__ \ (_| | |  | |_) | (_) | |_
+ |___/\__, |_|  |_.__/ \___/ \__|
+      |___/
+";
+
 struct Handler;
 
 #[async_trait]
@@ -27,12 +39,32 @@ async fn main() -> Result<()> {
     // enable logging with tracing
     tracing_subscriber::fmt::init();
 
+    // the banner is opt-in so it doesn't end up in structured log pipelines;
+    // `SHOW_STARTUP_BANNER` accepts `1` or `true` (case-insensitive)
+    if env::var("SHOW_STARTUP_BANNER")
+        .is_ok_and(|value| value == "1" || value.eq_ignore_ascii_case("true"))
+    {
+        println!("{}", BANNER);
+    }
+
+    let commands = commands::commands();
+    info!(
+        version = VERSION,
+        git_sha = GIT_SHA,
+        commands = ?commands.iter().map(|command| &command.name).collect::<Vec<_>>(),
+        "Starting sgrbot v{}",
+        VERSION
+    );
+
     let token = env::var("DISCORD_TOKEN").expect("`DISCORD_TOKEN` should be in the environment");
     let intents = GatewayIntents::non_privileged();
 
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
-            commands: commands::commands(),
+            commands,
+            pre_command: |ctx| Box::pin(commands::pre_command(ctx)),
+            post_command: |ctx| Box::pin(commands::post_command(ctx)),
+            on_error: |error| Box::pin(commands::on_error(error)),
             ..Default::default()
         })
         .setup(|ctx, _ready, framework| {